@@ -0,0 +1,110 @@
+///
+/// INTERLOCK NETWORK MVP CAP TABLE HELPER
+///  - BATCH STAKEHOLDER REGISTRATION
+///
+/// Client-side helper that turns a CSV cap table into
+/// the encoded register_stakeholders message call.
+///
+/// #### CSV format, one stakeholder per line:
+///
+/// account,share,pool
+/// 0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d,1000000000000000000000,0
+///
+/// - account is the hex encoded 32B account id.
+/// - share is in ILOCK including decimals.
+/// - pool is the pool number.
+/// - Header line, blank lines and lines starting with # are skipped.
+///
+
+use crate::ilockmvp::ID_LENGTH;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use scale::Encode;
+
+/// - Cap table parse errors, each carrying the offending line number.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CapTableError {
+    /// - Returned if line does not have exactly three fields.
+    InvalidLine(usize),
+    /// - Returned if account is not 0x prefixed 32B hex.
+    InvalidAccount(usize),
+    /// - Returned if share is not an integer.
+    InvalidShare(usize),
+    /// - Returned if pool is not an integer 0-255.
+    InvalidPool(usize),
+}
+
+/// - Parse CSV cap table into register_stakeholders entries.
+pub fn parse_cap_table(
+    csv: &str,
+) -> Result<Vec<(AccountId, Balance, u8)>, CapTableError> {
+
+    let mut stakeholders: Vec<(AccountId, Balance, u8)> = Vec::new();
+    for (index, line) in csv.lines().enumerate() {
+
+        // line numbers count from one
+        let number: usize = index.saturating_add(1);
+        let line: &str = line.trim();
+
+        // skip blanks, comments and header
+        if line.is_empty() || line.starts_with('#') || line.starts_with("account") {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(|field| field.trim()).collect();
+        if fields.len() != 3 {
+            return Err(CapTableError::InvalidLine(number));
+        }
+
+        let account: AccountId = match parse_account(fields[0]) {
+            Some(account) => account,
+            None => return Err(CapTableError::InvalidAccount(number)),
+        };
+        let share: Balance = match fields[1].parse::<Balance>() {
+            Ok(share) => share,
+            Err(_) => return Err(CapTableError::InvalidShare(number)),
+        };
+        let pool: u8 = match fields[2].parse::<u8>() {
+            Ok(pool) => pool,
+            Err(_) => return Err(CapTableError::InvalidPool(number)),
+        };
+
+        stakeholders.push((account, share, pool));
+    }
+
+    Ok(stakeholders)
+}
+
+/// - Encode CSV cap table as register_stakeholders call data (selector followed by args).
+pub fn encode_register_stakeholders(
+    csv: &str,
+) -> Result<Vec<u8>, CapTableError> {
+
+    let stakeholders: Vec<(AccountId, Balance, u8)> = parse_cap_table(csv)?;
+
+    let mut calldata: Vec<u8> = ink::selector_bytes!("register_stakeholders").to_vec();
+    stakeholders.encode_to(&mut calldata);
+
+    Ok(calldata)
+}
+
+/// - Helper to parse 0x prefixed hex account id.
+fn parse_account(
+    hex: &str,
+) -> Option<AccountId> {
+
+    let hex: &str = hex.strip_prefix("0x")?;
+    if hex.len() != 2 * ID_LENGTH || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut bytes: [u8; ID_LENGTH] = [0; ID_LENGTH];
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let pair: &str = core::str::from_utf8(pair).ok()?;
+        *byte = u8::from_str_radix(pair, 16).ok()?;
+    }
+
+    Some(AccountId::from(bytes))
+}
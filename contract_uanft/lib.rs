@@ -47,6 +47,7 @@ pub mod uanft {
         },
         reflect::ContractEventBase,
     };
+    use scale::{Encode, Decode};
 
     // openbrush 3 imports
     use openbrush::{
@@ -74,19 +75,52 @@ pub mod uanft {
         ILOCKmvpRef,
     };
 
-    /// - Constants.
-    pub const TIME_LIMIT_MIN: Timestamp = 600_000;      // ten minutes
-    pub const THRESHOLD_MIN: u16 = 2;                   // two signers 
-    
+    // shared Interlock Network multisig
+    use multisig::{
+        Multisig,
+        Internal as MultisigInternal,
+        MultisigResult,
+        SharedFunction,
+        MultisigError,
+        AccountID,
+        TIME_LIMIT_MIN,
+    };
+
+    /// - Multisig function indices.
+    pub const TRANSFER_OWNERSHIP: u8   = MultisigFunction::TransferOwnership as u8;
+    pub const UNPAUSE: u8              = MultisigFunction::Unpause as u8;
+    pub const ADD_SIGNATORY: u8        = MultisigFunction::AddSignatory as u8;
+    pub const REMOVE_SIGNATORY: u8     = MultisigFunction::RemoveSignatory as u8;
+    pub const CHANGE_TIMELIMIT: u8     = MultisigFunction::ChangeTimelimit as u8;
+    pub const CHANGE_THRESHOLD: u8     = MultisigFunction::ChangeThreshold as u8;
+    pub const UPDATE_CONTRACT: u8      = MultisigFunction::UpdateContract as u8;
+    pub const SET_EXECUTION_DELAY: u8  = MultisigFunction::SetExecutionDelay as u8;
+    pub const SET_GUARDIANS: u8        = MultisigFunction::SetGuardians as u8;
+    pub const SET_SIGNATORY_WEIGHT: u8 = MultisigFunction::SetSignatoryWeight as u8;
+
+    /// - Multisig functions of this contract, discriminant being the function index.
+    /// - Multisig messages (order_multisigtx, etc) take the function, and the shared
+    /// multisig keeps it by index, eg `MultisigFunction::Unpause as u8`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo)
+    )]
+    pub enum MultisigFunction {
+        TransferOwnership   = 0,
+        Unpause             = 1,
+        AddSignatory        = 2,
+        RemoveSignatory     = 3,
+        ChangeTimelimit     = 4,
+        ChangeThreshold     = 5,
+        UpdateContract      = 6,
+        SetExecutionDelay   = 7,
+        SetGuardians        = 8,
+        SetSignatoryWeight  = 9,
+    }
 
-    /// - Multisig functions.
-    pub const TRANSFER_OWNERSHIP: u8    = 0;
-    pub const UNPAUSE: u8               = 1;
-    pub const ADD_SIGNATORY: u8         = 2;
-    pub const REMOVE_SIGNATORY: u8      = 3;
-    pub const CHANGE_TIMELIMIT: u8      = 4;
-    pub const CHANGE_THRESHOLD: u8      = 5;
-    pub const UPDATE_CONTRACT: u8       = 6;
+    /// - Multisig execution delay for critical functions.
+    pub const EXECUTION_DELAY: Timestamp = 172_800_000; // 48 hours
 
     #[openbrush::wrapper]
     pub type Psp34Ref = dyn PSP34 + PSP34Metadata;
@@ -111,112 +145,6 @@ pub mod uanft {
     }
 
 
-    /// This is a type wrapper to implement Default method
-    /// on AccountId type. Ink 4 stable eliminated AccountId Default
-    /// (which was zero address, that has known private key)
-    /// ...we only really need this because Openbrush contract
-    ///    relies on deriving Default for contract storage, and
-    ///    our AccesData struct contains AccountId.
-    #[derive(scale::Encode, scale::Decode, Copy, Clone, Debug, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(
-            Eq,
-            scale_info::TypeInfo,
-            ink::storage::traits::StorageLayout,
-        )
-    )]
-    pub struct AccountID {
-        address: AccountId,
-    }
-    impl Default for AccountID {
-        fn default() -> AccountID {
-            AccountID {
-                address: AccountId::from([1_u8;32]),
-            }
-        }
-    }
-
-    /// - This is upgradable storage for the multisig feature of this
-    /// PSP22 contract (ie, the application/socket/port contract connectivity formalism).
-    pub const MULTISIG_KEY: u32 = openbrush::storage_unique_key!(MultisigData);
-    #[derive(Default, Debug)]
-    #[openbrush::upgradeable_storage(MULTISIG_KEY)]
-    pub struct MultisigData {
-
-        // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
-        // OR TYPES IF UPGRADING THIS CONTRACT!!!
-
-        /// - Stanging transaction
-        pub tx: Transaction,
-
-        /// - Vector of signatories.
-        pub signatories: Vec<AccountID>,
-        
-        /// - Multisig threshold..
-        pub threshold: u16,
-
-        /// - Multisig time limit.
-        pub timelimit: Timestamp,
-
-        /// - Expand storage related to the multisig functionality.
-        pub _reserved: Option<()>,
-    }
-    /// - TransactionData struct contains all pertinent information for multisigtx transaction
-    #[derive(scale::Encode, scale::Decode, Clone, Default, Debug)]
-    #[cfg_attr(
-    feature = "std",
-    derive(
-        PartialEq,
-        Eq,
-        scale_info::TypeInfo,
-        ink::storage::traits::StorageLayout,
-        )
-    )]
-
-    pub struct Transaction {
-
-        // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
-        // OR TYPES IF UPGRADING THIS CONTRACT!!!
-
-        /// - Which signatory ordered the multisigtx tx?
-        pub orderer: AccountID,
-
-        /// - What signatures have been collected?
-        pub signatures: Vec<Signature>,
-
-        /// - Which multisigtx function is being called?
-        pub function: u8,
-
-        /// - What is the timestamp on current transaction?
-        pub time: Timestamp,
-
-        /// - Was transaction completed?
-        pub ready: bool,
-    }
-    /// - TransactionData struct contains all pertinent information for multisigtx transaction
-    #[derive(scale::Encode, scale::Decode, Clone, Copy, Default, Debug)]
-    #[cfg_attr(
-    feature = "std",
-    derive(
-        PartialEq,
-        Eq,
-        scale_info::TypeInfo,
-        ink::storage::traits::StorageLayout,
-        )
-    )]
-    pub struct Signature {
-
-        // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
-        // OR TYPES IF UPGRADING THIS CONTRACT!!!
-
-        /// - Who signed this signature?
-        pub signer: AccountID,
-
-        /// - What is the timestamp on current transaction?
-        pub time: Timestamp,
-    }
-
     /// - This is upgradable storage for the access features for this
     /// universal access nft contract.
     pub const ACCESS_KEY: u32 = openbrush::storage_unique_key!(AccessData);
@@ -302,7 +230,7 @@ pub mod uanft {
 
         /// - ILOCK multisig contract info.
         #[storage_field]
-        multisig: MultisigData,
+        multisig: multisig::MultisigData,
 
         /// - Art zero storage fields.
         last_token_id: u64,
@@ -335,6 +263,108 @@ pub mod uanft {
         pub approved: bool,
     }
 
+    /// - Specify multisigtx ordered event.
+    #[ink(event)]
+    pub struct MultisigOrdered {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        #[ink(topic)]
+        pub proposer: AccountId,
+    }
+
+    /// - Specify multisigtx signed event.
+    #[ink(event)]
+    pub struct MultisigSigned {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        #[ink(topic)]
+        pub signer: AccountId,
+    }
+
+    /// - Specify multisigtx queued event.
+    #[ink(event)]
+    pub struct MultisigQueued {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        pub eta: Timestamp,
+    }
+
+    /// - Specify multisigtx vetoed event.
+    #[ink(event)]
+    pub struct MultisigVetoed {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+    }
+
+    /// - Specify multisigtx cancelled event.
+    #[ink(event)]
+    pub struct MultisigCancelled {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        #[ink(topic)]
+        pub proposer: AccountId,
+    }
+
+    /// - Specify multisigtx signature revoked event.
+    #[ink(event)]
+    pub struct SignatureRevoked {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        #[ink(topic)]
+        pub signer: AccountId,
+    }
+
+    /// - Specify multisigtx executed event.
+    #[ink(event)]
+    pub struct MultisigExecuted {
+        pub proposal: u32,
+        #[ink(topic)]
+        pub function: u8,
+        #[ink(topic)]
+        pub proposer: AccountId,
+        pub executor: AccountId,
+    }
+
+    /// - Specify signatory added event.
+    #[ink(event)]
+    pub struct SignatoryAdded {
+        #[ink(topic)]
+        pub signatory: AccountId,
+    }
+
+    /// - Specify signatory removed event.
+    #[ink(event)]
+    pub struct SignatoryRemoved {
+        #[ink(topic)]
+        pub signatory: AccountId,
+    }
+
+    /// - Specify signatory weight changed event.
+    #[ink(event)]
+    pub struct SignatoryWeightChanged {
+        #[ink(topic)]
+        pub signatory: AccountId,
+        pub weight: u16,
+    }
+
+    /// - Specify multisig threshold changed event.
+    #[ink(event)]
+    pub struct ThresholdChanged {
+        pub threshold: u16,
+    }
+
+    /// - Specify multisigtx timelimit changed event.
+    #[ink(event)]
+    pub struct TimelimitChanged {
+        pub timelimit: Timestamp,
+    }
+
     /// - For Pausable functions that are only_owner.
     impl From<PausableError> for Error {
         fn from(error: PausableError) -> Self {
@@ -349,6 +379,13 @@ pub mod uanft {
         }
     }
 
+    /// - For shared multisig functions.
+    impl From<MultisigError> for Error {
+        fn from(error: MultisigError) -> Self {
+            Error::Custom(format!("{:?}", error))
+        }
+    }
+
     /// - Needed for Openbrush internal event emission implementations.
     pub type Event = <Psp34Nft as ContractEventBase>::Type;
 
@@ -454,6 +491,212 @@ pub mod uanft {
     }
 
     impl PSP34Metadata for Psp34Nft {}
+
+    impl Multisig for Psp34Nft {}
+
+    impl MultisigInternal for Psp34Nft {
+
+        /// - Accept UANFT multisig function indices.
+        fn _is_multisig_function(
+            &self,
+            function: u8,
+        ) -> bool {
+
+            MultisigFunction::decode(&mut &[function][..]).is_ok()
+        }
+
+        /// - Give shared multisig-gated messages their UANFT multisig function index.
+        fn _shared_function(
+            &self,
+            function: SharedFunction,
+        ) -> MultisigResult<u8> {
+
+            Ok(match function {
+                SharedFunction::AddSignatory => ADD_SIGNATORY,
+                SharedFunction::RemoveSignatory => REMOVE_SIGNATORY,
+                SharedFunction::ChangeTimelimit => CHANGE_TIMELIMIT,
+                SharedFunction::ChangeThreshold => CHANGE_THRESHOLD,
+                SharedFunction::SetExecutionDelay => SET_EXECUTION_DELAY,
+                SharedFunction::SetGuardians => SET_GUARDIANS,
+                SharedFunction::SetSignatoryWeight => SET_SIGNATORY_WEIGHT,
+            })
+        }
+
+        /// - Impliment multisig event emission.
+        fn _emit_multisig_ordered_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _proposer: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigOrdered(MultisigOrdered {
+                    proposal: _proposal,
+                    function: _function,
+                    proposer: _proposer,
+                }),
+            );
+        }
+
+        fn _emit_multisig_signed_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _signer: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigSigned(MultisigSigned {
+                    proposal: _proposal,
+                    function: _function,
+                    signer: _signer,
+                }),
+            );
+        }
+
+        fn _emit_multisig_queued_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _eta: Timestamp,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigQueued(MultisigQueued {
+                    proposal: _proposal,
+                    function: _function,
+                    eta: _eta,
+                }),
+            );
+        }
+
+        fn _emit_multisig_vetoed_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigVetoed(MultisigVetoed {
+                    proposal: _proposal,
+                    function: _function,
+                }),
+            );
+        }
+
+        fn _emit_multisig_cancelled_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _proposer: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigCancelled(MultisigCancelled {
+                    proposal: _proposal,
+                    function: _function,
+                    proposer: _proposer,
+                }),
+            );
+        }
+
+        fn _emit_signature_revoked_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _signer: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::SignatureRevoked(SignatureRevoked {
+                    proposal: _proposal,
+                    function: _function,
+                    signer: _signer,
+                }),
+            );
+        }
+
+        fn _emit_multisig_executed_event(
+            &self,
+            _proposal: u32,
+            _function: u8,
+            _proposer: AccountId,
+            _executor: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::MultisigExecuted(MultisigExecuted {
+                    proposal: _proposal,
+                    function: _function,
+                    proposer: _proposer,
+                    executor: _executor,
+                }),
+            );
+        }
+
+        fn _emit_signatory_added_event(
+            &self,
+            _signatory: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::SignatoryAdded(SignatoryAdded {
+                    signatory: _signatory,
+                }),
+            );
+        }
+
+        fn _emit_signatory_removed_event(
+            &self,
+            _signatory: AccountId,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::SignatoryRemoved(SignatoryRemoved {
+                    signatory: _signatory,
+                }),
+            );
+        }
+
+        fn _emit_signatory_weight_changed_event(
+            &self,
+            _signatory: AccountId,
+            _weight: u16,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::SignatoryWeightChanged(SignatoryWeightChanged {
+                    signatory: _signatory,
+                    weight: _weight,
+                }),
+            );
+        }
+
+        fn _emit_threshold_changed_event(
+            &self,
+            _threshold: u16,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::ThresholdChanged(ThresholdChanged {
+                    threshold: _threshold,
+                }),
+            );
+        }
+
+        fn _emit_timelimit_changed_event(
+            &self,
+            _timelimit: Timestamp,
+        ) {
+            Psp34Nft::emit_event(
+                self.env(),
+                Event::TimelimitChanged(TimelimitChanged {
+                    timelimit: _timelimit,
+                }),
+            );
+        }
+    }
     impl PSP34Enumerable for Psp34Nft {}
 
     impl PSP34Burnable for Psp34Nft {
@@ -599,6 +842,12 @@ pub mod uanft {
             contract.multisig.timelimit = timelimit;
             contract.multisig.threshold = 2;
 
+            // critical multisig functions give holders notice before they take effect
+            contract.multisig.delays.insert(TRANSFER_OWNERSHIP, &EXECUTION_DELAY);
+            contract.multisig.delays.insert(UPDATE_CONTRACT, &EXECUTION_DELAY);
+            contract.multisig.delays.insert(SET_EXECUTION_DELAY, &EXECUTION_DELAY);
+            contract.multisig.delays.insert(SET_GUARDIANS, &EXECUTION_DELAY);
+
             // assign caller as owner
             contract._init_with_owner(caller);
 
@@ -619,388 +868,93 @@ pub mod uanft {
 /////// multisigtx /////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////
 //
-// Workflow:
-//
-// 1) signatory orders multisig transaction via order_multisigtx()
-//      ...this signatory's order is considered the first signature
-// 2) other signatories sign multisig transaction via sign_multisigtx()
-// 3) any signatory may check the number of signatures
-// 4) when signature count threshold is met, then any signatory may call specified function
+// Multisig messages that take a multisig function (order_multisigtx, sign_multisigtx, etc)
+// are defined here with MultisigFunction, so its variants are in contract metadata.
+// The rest (add_signatory, etc) come from the shared multisig crate,
+// via `impl Multisig for Psp34Nft`.
 //
-// - all signatories must agree on the function they are signing for (ie, the multisigtx ordered)
-// - to prevent case where corrupted signatory exists, no signatory may order a multisigtx
-//   consecutively. This is to prevent corrupted signatory from jamming up the multisig process
+// Messages below that are gated by multisigtx call _check_multisig() first, with their
+// own function index.
 //
 
-        /// - Helper function for checking signature count
-        pub fn check_multisig(
-            &self,
-            function: String,
-        ) -> OtherResult<()> {
-
-            let caller: AccountID = AccountID { address: self.env().caller() };
-            let thistime: Timestamp = self.env().block_timestamp();
-
-            // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
-
-                return Err(Error::Custom(format!("CallerNotSignatory")));
-            }
-
-            // if enough signatures had not been supplied, revert
-            if self.multisig.tx.signatures.len() < self.multisig.threshold as usize {
-
-                return Err(Error::Custom(format!("NotEnoughSignatures")));
-            }
-
-            // if multisigtx is too old, then signature does not matter
-            if thistime - self.multisig.tx.time >= self.multisig.timelimit {
-
-                return Err(Error::Custom(format!("TransactionStale")));
-            }
-
-            // get function index
-            let function: u8 = match function.as_str() {
-                "TRANSFER_OWNERSHIP"    => TRANSFER_OWNERSHIP,
-                "UNPAUSE"               => UNPAUSE,
-                "ADD_SIGNATORY"         => ADD_SIGNATORY,
-                "REMOVE_SIGNATORY"      => REMOVE_SIGNATORY,
-                "CHANGE_THRESHOLD"      => CHANGE_THRESHOLD,
-                "CHANGE_TIMELIMIT"      => CHANGE_TIMELIMIT,
-                "UPDATE_CONTRACT"       => UPDATE_CONTRACT,
-                _ => return Err(Error::Custom(format!("InvalidFunction"))),
-            };
-
-            // signer must know they are signing for the right function
-            if function != self.multisig.tx.function {
-
-                return Err(Error::Custom(format!("WrongFunction")));
-            }
-
-            Ok(())
-        }
-
         /// - Function to order multisigtx transaction.
+        /// - Args are the SCALE-encoded arguments of the function being ordered
+        /// (excluding the trailing `proposal` argument).
+        /// - Returns the proposal id signatories sign and call with.
         #[ink(message)]
         pub fn order_multisigtx(
             &mut self,
-            function: String,
-        ) -> OtherResult<()> {
+            function: MultisigFunction,
+            args: Vec<u8>,
+        ) -> MultisigResult<u32> {
 
-            let caller: AccountID = AccountID { address: self.env().caller() };
-            let thistime: Timestamp = self.env().block_timestamp();
-
-            // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
-
-                return Err(Error::Custom(format!("CallerNotSignatory")));
-            }
-
-            // if the signing period has already begun, orderer
-            if thistime - self.multisig.tx.time < self.multisig.timelimit {
-
-                return Err(Error::Custom(format!("TransactionAlreadyOrdered")));
-            }
-
-            // this is important to prevent corrupted key from 'freezing out'
-            // other signatories' ability to order transaction
-            if thistime - self.multisig.tx.time >= self.multisig.timelimit
-                && caller == self.multisig.tx.orderer {
-
-                return Err(Error::Custom(format!("CannotReorder")));
-            }
-
-            // get function index
-            let function: u8 = match function.as_str() {
-                "TRANSFER_OWNERSHIP"    => TRANSFER_OWNERSHIP,
-                "UNPAUSE"               => UNPAUSE,
-                "ADD_SIGNATORY"         => ADD_SIGNATORY,
-                "REMOVE_SIGNATORY"      => REMOVE_SIGNATORY,
-                "CHANGE_THRESHOLD"      => CHANGE_THRESHOLD,
-                "CHANGE_TIMELIMIT"      => CHANGE_TIMELIMIT,
-                "UPDATE_CONTRACT"       => UPDATE_CONTRACT,
-                _ => return Err(Error::Custom(format!("InvalidFunction"))),
-            };
-
-            // set transaction function
-            self.multisig.tx.function = function;
-
-            // set transaction order time
-            self.multisig.tx.time = thistime;
-
-            // construct signature
-            let signature: Signature = Signature {
-                signer: caller,
-                time: thistime,
-            };
-
-            // add first signature to multisigtx transaction order
-            self.multisig.tx.signatures = Vec::new();
-            self.multisig.tx.signatures.push(signature);
-
-            Ok(())
+            self._order_multisigtx(function as u8, args)
         }
 
         /// - A multisigtx signer calls this to sign.
+        /// - Signer must supply the same function and SCALE-encoded arguments as the order.
         #[ink(message)]
         pub fn sign_multisigtx(
             &mut self,
-            function: String,
-        ) -> OtherResult<()> {
-
-            let caller: AccountID = AccountID { address: self.env().caller() };
-            let thistime: Timestamp = self.env().block_timestamp();
-
-            // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
+            proposal: u32,
+            function: MultisigFunction,
+            args: Vec<u8>,
+        ) -> MultisigResult<()> {
 
-                return Err(Error::Custom(format!("CallerNotSignatory")));
-            }
-
-            // get function index
-            let function: u8 = match function.as_str() {
-                "TRANSFER_OWNERSHIP"    => TRANSFER_OWNERSHIP,
-                "UNPAUSE"               => UNPAUSE,
-                "ADD_SIGNATORY"         => ADD_SIGNATORY,
-                "REMOVE_SIGNATORY"      => REMOVE_SIGNATORY,
-                "CHANGE_THRESHOLD"      => CHANGE_THRESHOLD,
-                "CHANGE_TIMELIMIT"      => CHANGE_TIMELIMIT,
-                "UPDATE_CONTRACT"       => UPDATE_CONTRACT,
-                _ => return Err(Error::Custom(format!("InvalidFunction"))),
-            };
-
-            // signer must know they are signing for the right function
-            if function != self.multisig.tx.function {
-
-                return Err(Error::Custom(format!("WrongFunction")));
-            }
-
-            // if multisigtx is too old, then signature does not matter
-            if thistime - self.multisig.tx.time >= self.multisig.timelimit {
-
-                return Err(Error::Custom(format!("TransactionStale")));
-            }
-
-            // make sure signatory has not already signed for the transaction
-            if self.multisig.tx.signatures.iter().any(|sig| sig.signer == caller) {
-
-                return Err(Error::Custom(format!("AlreadySigned")));
-            }
-
-            // construct signature
-            let signature: Signature = Signature {
-                signer: caller,
-                time: thistime,
-            };
-
-            self.multisig.tx.signatures.push(signature);
-
-            Ok(())
+            self._sign_multisigtx(proposal, function as u8, args)
         }
 
-        /// - This adds a signatory from the list of permitted signatories.
+        /// - Anyone may call this to record a multisigtx signed off-chain by signatories.
+        /// - Signatures are 65 byte ECDSA signatures over proposal_hash(function, nonce, args).
+        /// - Signatures must meet threshold; returns the proposal id to call with.
+        /// - First signer stands in as orderer, and may not have another open order.
         #[ink(message)]
-        pub fn add_signatory(
+        pub fn submit_signatures(
             &mut self,
-            signatory: AccountId,
-            function: String,
-        ) -> OtherResult<()> {
-    
-            // verify multisig good
-            let _ = self.check_multisig(function)?;
+            function: MultisigFunction,
+            nonce: u64,
+            args: Vec<u8>,
+            signatures: Vec<[u8; 65]>,
+        ) -> MultisigResult<u32> {
 
-            // make sure signatory is not zero address
-            if signatory == AccountId::from([0_u8; 32]) {
-
-                return Err(Error::Custom(format!("IsZeroAddress")));
-            }
-
-            let signatory: AccountID = AccountID { address: signatory };
-
-            // make sure caller is designated multisigtx account
-            if self.multisig.signatories.contains(&signatory) {
-
-                return Err(Error::Custom(format!("AlreadySignatory")));
-            }
-
-            self.multisig.signatories.push(signatory);
-
-            Ok(())
-        }
-
-        /// - This removes a signatory from the list of permitted signatories.
-        #[ink(message)]
-        pub fn remove_signatory(
-            &mut self,
-            signatory: AccountId,
-            function: String,
-        ) -> OtherResult<()> {
-        
-            // verify multisig good
-            let _ = self.check_multisig(function)?;
-
-            // make sure signatory is not zero address
-            if signatory == AccountId::from([0_u8; 32]) {
-
-                return Err(Error::Custom(format!("IsZeroAddress")));
-            }
-
-            let signatory: AccountID = AccountID { address: signatory };
-
-            // make sure signatory is designated multisigtx account
-            if !self.multisig.signatories.contains(&signatory) {
-
-                return Err(Error::Custom(format!("NoSignatory")));
-            }
-
-            // contract must maintain THRESHOLD + 1 signatories at all times
-            let neededsignatories: u16 = match self.multisig.threshold.checked_add(1) {
-                Some(sum) => sum,
-                None => return Err(Error::Custom(format!("Overflow"))),
-            };
-
-            // make sure there are enough signatories for new threshold
-            if self.multisig.signatories.len() <= neededsignatories.into() {
-
-                return Err(Error::Custom(format!("TooFewSignatories")));
-            }
-
-            self.multisig.signatories.retain(|&account| account != signatory);
-
-            Ok(())
+            self._record_signed_multisigtx(function as u8, nonce, args, signatures)
         }
 
-        /// - This changes signer threshold for approving multisigtx.
+        /// - This gets the hash signatories sign off-chain for submit_signatures().
+        /// - Hash is blake2x256 of SCALE-encoded (contract address, nonce, function, args).
+        /// - Nonce is any number not yet used (see nonce_used()), picked per proposal.
         #[ink(message)]
-        pub fn change_threshold(
-            &mut self,
-            threshold: u16,
-            function: String,
-        ) -> OtherResult<()> {
-    
-            // verify multisig good
-            let _ = self.check_multisig(function)?;
-
-            // make sure new threshold is greater then minimum
-            if threshold < THRESHOLD_MIN {
-
-                return Err(Error::Custom(format!("UnderThresholdMin")));
-            }
-
-            // contract must maintain THRESHOLD + 1 signatories at all times
-            let neededsignatories: u16 = match threshold.checked_add(1) {
-                Some(sum) => sum,
-                None => return Err(Error::Custom(format!("Overflow"))),
-            };
-
-            // make sure there are enough signatories for new threshold
-            if self.multisig.signatories.len() < neededsignatories.into() {
-
-                return Err(Error::Custom(format!("TooFewSignatories")));
-            }
-
-            self.multisig.threshold = threshold;
-
-            Ok(())
-        }
-
-        /// - This modifies timelimit for a multisig transaction.
-        #[ink(message)]
-        pub fn change_multisigtxtimelimit(
-            &mut self,
-            timelimit: Timestamp,
-            function: String,
-        ) -> OtherResult<()> {
-    
-            // verify multisig good
-            let _ = self.check_multisig(function)?;
-
-            // make sure limit is respected
-            if timelimit < TIME_LIMIT_MIN {
-
-                return Err(Error::Custom(format!("UnderTimeLimit")));
-            }
-
-            self.multisig.timelimit = timelimit;
-
-            Ok(())
-        }
-
-        /// - This gets the current signature threshold for multisigtx.
-        #[ink(message)]
-        pub fn threshold(
-            &self,
-        ) -> u16 {
-
-            self.multisig.threshold
-        }
-
-        /// - This gets the current timelimit for signatories to sign multisigtx.
-        #[ink(message)]
-        pub fn multisigtimelimit(
+        pub fn proposal_hash(
             &self,
-        ) -> Timestamp {
+            function: MultisigFunction,
+            nonce: u64,
+            args: Vec<u8>,
+        ) -> [u8; 32] {
 
-            self.multisig.timelimit
+            self._proposal_hash(function as u8, nonce, &args)
         }
 
-        /// - This gets a list of current accounts permitted to sign multisigtx.
+        /// - This sets the execution delay for a multisigtx function.
+        /// - Multisigtx args are SCALE-encoded (target, delay).
         #[ink(message)]
-        pub fn signatories(
+        pub fn set_execution_delay(
             &mut self,
-        ) -> OtherResult<Vec<AccountID>> {
-
-            let caller: AccountID = AccountID { address: self.env().caller() };
-
-            // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
+            target: MultisigFunction,
+            delay: Timestamp,
+            proposal: u32,
+        ) -> MultisigResult<()> {
 
-                return Err(Error::Custom(format!("CallerNotSignatory")));
-            }
-            
-            Ok(self.multisig.signatories.iter().map(|sig| *sig ).collect())
-        }
-
-        /// - This gets number of signatories permitted to sign multisigtx.
-        #[ink(message)]
-        pub fn signatory_count(
-            &self,
-        ) -> u8 {
-
-            self.multisig.signatories.len() as u8
+            self._set_execution_delay(target as u8, delay, proposal)
         }
 
-        /// - This gets current number of signatures for multisigtx.
+        /// - This gets the execution delay for a multisigtx function.
         #[ink(message)]
-        pub fn signature_count(
+        pub fn execution_delay(
             &self,
-        ) -> u8 {
+            target: MultisigFunction,
+        ) -> MultisigResult<Timestamp> {
 
-            self.multisig.tx.signatures.len() as u8
-        }
-
-        /// - This gets a list of all signers so far on a multisigtx.
-        #[ink(message)]
-        pub fn check_signatures(
-            &mut self,
-        ) -> OtherResult<Vec<Signature>> {
-
-            let thistime: Timestamp = self.env().block_timestamp();
-            let caller: AccountID = AccountID { address: self.env().caller() };
-
-            // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
-
-                return Err(Error::Custom(format!("CallerNotSignatory")));
-            }
-
-            // if multisigtx is too old, then it doesn't matter who signed
-            if thistime - self.multisig.tx.time > self.multisig.timelimit {
-
-                return Err(Error::Custom(format!("TransactionStale")));
-            }
-
-            Ok(self.multisig.tx.signatures.iter().map(|sig| *sig ).collect())
+            self._execution_delay(target as u8)
         }
 
 ////////////////////////////////////////////////////////////////////////////
@@ -1014,10 +968,8 @@ pub mod uanft {
             &mut self,
         ) -> OtherResult<()> {
 
-            let caller: AccountID = AccountID { address: self.env().caller() };
-
             // make sure caller is designated multisigtx account
-            if !self.multisig.signatories.contains(&caller) {
+            if !self._is_signatory(self.env().caller()) {
 
                 return Err(Error::Custom(format!("CallerNotSignatory")));
             }
@@ -1029,11 +981,11 @@ pub mod uanft {
         #[ink(message)]
         pub fn unpause(
             &mut self,
-            function: String,
+            proposal: u32,
         ) -> OtherResult<()> {
     
             // verify multisig good
-            let _ = self.check_multisig(function)?;
+            let _ = self._check_multisig(proposal, UNPAUSE, Vec::new())?;
 
             self._unpause()
         }
@@ -1287,11 +1239,11 @@ pub mod uanft {
         pub fn transfer_ownership(
             &mut self,
             newowner: AccountId,
-            function: String,
+            proposal: u32,
         ) -> OtherResult<()> {
     
             // verify multisig good
-            let _ = self.check_multisig(function)?;
+            let _ = self._check_multisig(proposal, TRANSFER_OWNERSHIP, newowner.encode())?;
 
             // make sure interlocker is not zero address
             if newowner == AccountId::from([0_u8; 32]) {
@@ -1310,11 +1262,11 @@ pub mod uanft {
         pub fn update_contract(
             &mut self,
             code_hash: [u8; 32],
-            function: String, 
+            proposal: u32,
         ) -> OtherResult<()> {
     
             // verify multisig good
-            let _ = self.check_multisig(function)?;
+            let _ = self._check_multisig(proposal, UPDATE_CONTRACT, code_hash.encode())?;
 
             // takes code hash of updates contract and modifies preexisting logic to match
             ink::env::set_code_hash(&code_hash).unwrap_or_else(|err| {
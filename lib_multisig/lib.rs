@@ -0,0 +1,2073 @@
+//!
+//! # INTERLOCK NETWORK - MULTISIG
+//!
+//! This is the multisig shared by Interlock Network contracts (ILOCK PSP22, UANFT PSP34),
+//! written in the style of an Openbrush contract extension: a storage struct, a trait
+//! of multisig messages with default implementations, and an internal trait for the
+//! pieces each contract customizes.
+//!
+//! #### To use in a contract:
+//!
+//! - add `multisig::MultisigData` as a `#[storage_field]` of contract storage
+//! - implement `Multisig` for the contract storage struct
+//! - define the contract's multisig function indices, and override
+//! `Internal::_is_multisig_function` to accept them
+//! - override `Internal::_shared_function` to give the multisig-gated messages
+//! of `Multisig` their index
+//! - call `Internal::_check_multisig` with the message's own function index
+//! at the top of every multisig-gated message
+//! - define `order_multisigtx`, `sign_multisigtx`, `submit_signatures`,
+//! `proposal_hash`, `set_execution_delay` and `execution_delay` as contract
+//! messages taking the contract's multisig function type, calling the matching
+//! `Internal` helpers (an ink trait definition cannot be generic over that type,
+//! and taking the type itself puts its variants in contract metadata)
+//!
+//! #### To build docs, run:
+//!
+//! -     cargo +nightly doc --no-deps --document-private-items --open
+//!
+
+#![doc(
+    html_logo_url = "https://assets-global.website-files.com/64d9930f57641d176ab09b78/64dde3b1459a01ddf7b4a529_interlock-logo-large.webp",
+    html_favicon_url = "https://assets-global.website-files.com/64d9930f57641d176ab09b78/64da50c8875e833f16060147_Favicon.png",
+)]
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+use ink::prelude::vec::Vec;
+use ink::storage::{
+    Lazy,
+    Mapping,
+};
+use ink::env::hash::Blake2x256;
+use openbrush::traits::{
+    AccountId,
+    DefaultEnv,
+    Storage,
+    Timestamp,
+};
+
+////////////////////////////////////////////////////////////////////////////
+//// constants /////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////
+
+/// - Magic numbers.
+pub const TIME_LIMIT_MIN: Timestamp = 600_000;                  // 10 minutes
+pub const THRESHOLD_MIN: u16 = 2;                               // two signers
+
+////////////////////////////////////////////////////////////////////////////
+//// structured data ///////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////
+
+/// This is a type wrapper to implement Default method
+/// on AccountId type. Ink 4 stable eliminated AccountId Default
+/// (which was zero address, that has known private key)
+/// ...we only really need this because Openbrush contract
+///    relies on deriving Default for contract storage, and
+///    our MultisigData struct contains AccountId.
+#[derive(scale::Encode, scale::Decode, Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(
+        Eq,
+        scale_info::TypeInfo,
+        ink::storage::traits::StorageLayout,
+    )
+)]
+pub struct AccountID {
+    pub address: AccountId,
+}
+impl Default for AccountID {
+    fn default() -> AccountID {
+        AccountID {
+            address: AccountId::from([1_u8;32]),
+        }
+    }
+}
+
+/// - This is upgradable storage for the multisig feature of a contract.
+/// - Packed fields match the MultisigData each contract declared before the multisig
+/// was shared, so deployed contracts keep their signatories, threshold and timelimit
+/// when upgraded. Everything added since is a Mapping or Lazy field, which take no
+/// room in the packed encoding.
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(MultisigData);
+#[derive(Default, Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct MultisigData {
+
+    // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
+    // OR TYPES IF UPGRADING THIS CONTRACT!!!
+
+    /// - Staging transaction of the original one-order-at-a-time multisig.
+    /// - Unused since proposals are kept by id, but must stay for storage to decode.
+    pub tx: LegacyTransaction,
+
+    /// - Vector of signatories.
+    pub signatories: Vec<AccountID>,
+
+    /// - Multisig threshold, in signature weight.
+    pub threshold: u16,
+
+    /// - Multisig time limit.
+    pub timelimit: Timestamp,
+
+    /// - Contains every multisigtx transaction ordered, by proposal id.
+    ///
+    /// proposals:         proposal id -> transaction
+    pub proposals: Mapping<u32, Transaction>,
+
+    /// - Proposal id to assign to the next multisigtx order.
+    pub nextproposal: Lazy<u32>,
+
+    /// - Ids of proposals that may still be signed or called.
+    pub open: Lazy<Vec<u32>>,
+
+    /// - Delay between a multisigtx meeting threshold and it being callable.
+    /// - Functions with no delay are callable as soon as threshold is met.
+    ///
+    /// delays:         function index -> execution delay
+    pub delays: Mapping<u8, Timestamp>,
+
+    /// - Vector of guardians that may veto multisigtx during execution delay.
+    pub guardians: Lazy<Vec<AccountID>>,
+
+    /// - Number of guardian vetoes needed to veto multisigtx.
+    pub guardianthreshold: Lazy<u16>,
+
+    /// - Signature weight of each signatory, counted toward threshold.
+    /// - Signatories with no weight set carry weight of one.
+    ///
+    /// weights:         signatory -> weight
+    pub weights: Mapping<AccountId, u16>,
+
+    /// - Nonces of off-chain signed multisigtxs already submitted.
+    /// - Each off-chain proposal commits to its own nonce, so several can be signed at once.
+    ///
+    /// nonces:         nonce -> used
+    pub nonces: Mapping<u64, bool>,
+
+    /// - Proposal whose call the contract is dispatching right now, if any.
+    /// - Contracts that dispatch proposal calls set this for the duration of the call only.
+    pub executing: Lazy<Option<u32>>,
+
+    /// - Expand storage related to the multisig functionality.
+    pub _reserved: Option<()>,
+}
+/// - LegacyTransaction is the staging transaction layout of the original multisig.
+#[derive(scale::Encode, scale::Decode, Clone, Default, Debug)]
+#[cfg_attr(
+feature = "std",
+derive(
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    ink::storage::traits::StorageLayout,
+    )
+)]
+pub struct LegacyTransaction {
+
+    // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
+    // OR TYPES IF UPGRADING THIS CONTRACT!!!
+
+    /// - Which signatory ordered the multisigtx tx?
+    pub orderer: AccountID,
+
+    /// - What signatures have been collected?
+    pub signatures: Vec<Signature>,
+
+    /// - Which multisigtx function is being called?
+    pub function: u8,
+
+    /// - What is the timestamp on current transaction?
+    pub time: Timestamp,
+
+    /// - Was transaction completed?
+    pub complete: bool,
+}
+/// - TransactionData struct contains all pertinent information for multisigtx transaction
+#[derive(scale::Encode, scale::Decode, Clone, Default, Debug)]
+#[cfg_attr(
+feature = "std",
+derive(
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    ink::storage::traits::StorageLayout,
+    )
+)]
+pub struct Transaction {
+
+    // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
+    // OR TYPES IF UPGRADING THIS CONTRACT!!!
+
+    /// - Which signatory ordered the multisigtx tx?
+    pub orderer: AccountID,
+
+    /// - What signatures have been collected?
+    pub signatures: Vec<Signature>,
+
+    /// - Which multisigtx function is being called?
+    pub function: u8,
+
+    /// - What is the timestamp on current transaction?
+    pub time: Timestamp,
+
+    /// - Was transaction completed?
+    pub complete: bool,
+
+    /// - What are the SCALE-encoded arguments the function will be called with?
+    /// - Every signature commits to these exact arguments.
+    pub args: Vec<u8>,
+
+    /// - Was transaction cancelled by orderer?
+    pub cancelled: bool,
+
+    /// - When did transaction meet signature threshold? (zero if not yet)
+    /// - Execution delay counts from this time.
+    pub queued: Timestamp,
+
+    /// - Which guardians vetoed transaction?
+    pub vetoes: Vec<AccountID>,
+
+    /// - Did guardians veto transaction?
+    pub vetoed: bool,
+
+    /// - What execution delay did function have when transaction was queued?
+    /// - Changing function delay afterwards does not change this.
+    pub delay: Timestamp,
+}
+/// - TransactionData struct contains all pertinent information for multisigtx transaction
+#[derive(scale::Encode, scale::Decode, Clone, Copy, Default, Debug)]
+#[cfg_attr(
+feature = "std",
+derive(
+    PartialEq,
+    Eq,
+    scale_info::TypeInfo,
+    ink::storage::traits::StorageLayout,
+    )
+)]
+pub struct Signature {
+
+    // ABSOLUTELY DO NOT CHANGE THE ORDER OF THESE VARIABLES
+    // OR TYPES IF UPGRADING THIS CONTRACT!!!
+
+    /// - Who signed this signature?
+    pub signer: AccountID,
+
+    /// - What is the timestamp on current transaction?
+    pub time: Timestamp,
+}
+
+/// - Multisig-gated messages of the Multisig trait itself.
+/// - Each contract gives these their index among its own multisig functions.
+#[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SharedFunction {
+    AddSignatory,
+    RemoveSignatory,
+    ChangeTimelimit,
+    ChangeThreshold,
+    SetExecutionDelay,
+    SetGuardians,
+    SetSignatoryWeight,
+}
+
+////////////////////////////////////////////////////////////////////////////
+//// errors ////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////
+
+/// - Multisig error types.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo)
+)]
+pub enum MultisigError {
+    /// - Returned if caller is not signatory.
+    CallerNotSignatory,
+    /// - Returned if caller did not order the multisig transaction.
+    CallerNotOrderer,
+    /// - Returned if multisigtx transaction does not exist for proposal id.
+    NoTransaction,
+    /// - Returned if multisigtx transaction was already completed.
+    TransactionAlreadyCompleted,
+    /// - Returned if multisig transaction has already been called.
+    TransactionAlreadyCalled,
+    /// - Returned if specified multisigtx function is not supported by contract.
+    InvalidFunction,
+    /// - Returned if caller is ordering a transaction while their previous order is open.
+    CannotReorder,
+    /// - Returned if function spacified by signer does not match order.
+    WrongFunction,
+    /// - Returned if arguments specified by signer or caller do not match order.
+    WrongArguments,
+    /// - Returned if multisigtx is too old.
+    TransactionStale,
+    /// - Returned if there are not enough signatures to call function.
+    NotEnoughSignatures,
+    /// - Returned if signer already signed.
+    AlreadySigned,
+    /// - Returned if signatory to add is already in vector.
+    AlreadySignatory,
+    /// - Returned if new timelimit is under time minimum.
+    UnderTimeMin,
+    /// - Returned if new threshold is under threshold minimum..
+    UnderThresholdMin,
+    /// - Returned if too few signatories.
+    TooFewSignatories,
+    /// - Returned if signatory not present.
+    NoSignatory,
+    /// - Returned if zero address.
+    IsZeroAddress,
+    /// - Returned if multisigtx was cancelled by orderer.
+    TransactionCancelled,
+    /// - Returned if orderer tries to revoke signature instead of cancelling order.
+    CallerIsOrderer,
+    /// - Returned if signatory has not signed the multisigtx.
+    NotSigned,
+    /// - Returned if multisigtx with execution delay is not queued, or its delay has not passed.
+    ExecutionDelayNotPassed,
+    /// - Returned if multisigtx was vetoed by guardians.
+    TransactionVetoed,
+    /// - Returned if caller is not guardian.
+    CallerNotGuardian,
+    /// - Returned if guardian already vetoed.
+    AlreadyVetoed,
+    /// - Returned if guardian threshold does not fit guardians.
+    InvalidGuardianThreshold,
+    /// - Returned if signatory weight is zero.
+    InvalidWeight,
+    /// - Returned if single signatory weight would meet threshold alone.
+    WeightTooLarge,
+    /// - Returned if signature does not recover to public key.
+    InvalidSignature,
+    /// - Returned if off-chain signature signer is not signatory.
+    SignerNotSignatory,
+    /// - Returned if multisigtx was already queued.
+    AlreadyQueued,
+    /// - Returned if off-chain signed multisigtx nonce was already submitted.
+    NonceUsed,
+    /// - Returned if checked add overflows.
+    Overflow,
+}
+
+/// - Convenience Result Type.
+pub type MultisigResult<T> = core::result::Result<T, MultisigError>;
+
+////////////////////////////////////////////////////////////////////////////
+//// traits ////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////
+//
+// Workflow:
+//
+// 1) signatory orders multisig transaction via order_multisigtx()
+//      ...this signatory's order is considered the first signature
+//      ...the order is assigned a proposal id, returned to the orderer
+// 2) other signatories sign multisig transaction via sign_multisigtx(), by proposal id
+// 3) any signatory may check the number of signatures
+// 4) when signature count threshold is met, then any signatory may call specified function,
+//    passing the proposal id
+//
+// - several proposals may be open at once, each with its own signing period
+// - all signatories must agree on the function they are signing for (ie, the multisigtx ordered)
+// - all signatories must agree on the SCALE-encoded arguments the function will be called with
+//   ...the function caller must supply these same arguments for the call to go through
+// - to prevent case where corrupted signatory exists, no signatory may have more than one
+//   open order at a time. This is to prevent corrupted signatory from jamming up the multisig process
+// - the orderer may cancel their own order before it is called
+//   ...a cancelled order still counts as the orderer's open order until it goes stale,
+//      so cancelling cannot be used to get around the one open order rule
+// - other signatories may revoke their signature before the order is called
+// - alternatively, signatories may sign proposal_hash() off-chain (ECDSA), and anyone may relay
+//   the signatures via submit_signatures() to record a signed multisigtx in one transaction
+//   ...the hash commits to this contract address, a nonce, function and args,
+//      and the nonce is consumed on submission so signatures cannot be replayed
+//   ...signatories pick any unused nonce per proposal (see nonce_used()), so signatures
+//      for several proposals may be collected at once without invalidating each other
+//   ...ink 4 exposes no sr25519 verification to contracts, so only ECDSA signatories
+//      (AccountId = blake2_256 of compressed public key) can sign off-chain
+// - signatures count toward threshold by signatory weight (one unless set otherwise)
+//   ...no single signatory may carry threshold weight, and signatories without the heaviest
+//      signatory must still carry threshold weight (ie, THRESHOLD + 1 signatories in weight terms)
+// - functions may have an execution delay, counted from when signature threshold is met
+//   ...during the delay the multisigtx is queued (see queued_multisigtxs()), and
+//      guardians may veto it by guardian threshold
+//   ...the delay is fixed when the multisigtx is queued, so later delay changes do not apply to it
+//   ...multisigtx that comes to meet threshold other than by signature (eg threshold or
+//      weight change) is queued by any signatory via queue_multisigtx()
+//   ...once the delay passes, signatories have the timelimit to call the function
+//
+
+#[openbrush::wrapper]
+pub type MultisigRef = dyn Multisig;
+
+/// - Multisig messages shared by Interlock Network contracts.
+/// - Messages taking a multisig function are defined by each contract, see Internal.
+#[openbrush::trait_definition]
+pub trait Multisig {
+
+    /// - The orderer of a multisigtx calls this to cancel their order.
+    /// - Orderer may not order again until the cancelled order would have gone stale.
+    #[ink(message)]
+    fn cancel_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - A multisigtx signer calls this to withdraw their signature.
+    /// - Orderer may not revoke; orderer must cancel instead.
+    #[ink(message)]
+    fn revoke_signature(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - A guardian calls this to veto a multisigtx.
+    /// - Multisigtx is vetoed once guardian threshold is met.
+    #[ink(message)]
+    fn veto_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - A signatory calls this to queue a multisigtx that meets threshold but was not queued
+    /// by signing, eg because threshold or signatory weights changed since.
+    /// - Execution delay counts from this call.
+    #[ink(message)]
+    fn queue_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This replaces guardians and guardian threshold.
+    /// - Multisigtx args are SCALE-encoded (guardians, threshold).
+    #[ink(message)]
+    fn set_guardians(
+        &mut self,
+        guardians: Vec<AccountId>,
+        threshold: u16,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This sets the signature weight of a signatory.
+    /// - Multisigtx args are SCALE-encoded (signatory, weight).
+    #[ink(message)]
+    fn set_signatory_weight(
+        &mut self,
+        signatory: AccountId,
+        weight: u16,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This adds a signatory from the list of permitted signatories.
+    #[ink(message)]
+    fn add_signatory(
+        &mut self,
+        signatory: AccountId,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This removes a signatory from the list of permitted signatories.
+    #[ink(message)]
+    fn remove_signatory(
+        &mut self,
+        signatory: AccountId,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This changes signature weight threshold for approving multisigtx.
+    #[ink(message)]
+    fn change_threshold(
+        &mut self,
+        threshold: u16,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This modifies timelimit for a multisig transaction.
+    #[ink(message)]
+    fn change_multisigtxtimelimit(
+        &mut self,
+        timelimit: Timestamp,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - This gets the current signature threshold for multisigtx.
+    #[ink(message)]
+    fn threshold(
+        &self,
+    ) -> u16;
+
+    /// - This gets the current timelimit for signatories to sign multisigtx.
+    #[ink(message)]
+    fn multisigtimelimit(
+        &self,
+    ) -> Timestamp;
+
+    /// - This gets a list of current accounts permitted to sign multisigtx.
+    #[ink(message)]
+    fn signatories(
+        &self,
+    ) -> MultisigResult<Vec<AccountID>>;
+
+    /// - This gets number of signatories permitted to sign multisigtx.
+    #[ink(message)]
+    fn signatory_count(
+        &self,
+    ) -> u8;
+
+    /// - This gets a multisigtx proposal by id.
+    #[ink(message)]
+    fn multisigtx(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Transaction>;
+
+    /// - This gets all proposals still open for signing or calling, with their ids.
+    #[ink(message)]
+    fn open_multisigtxs(
+        &self,
+    ) -> Vec<(u32, Transaction)>;
+
+    /// - This gets all queued proposals waiting out their execution delay or callable,
+    /// with their ids and the time they become callable.
+    #[ink(message)]
+    fn queued_multisigtxs(
+        &self,
+    ) -> Vec<(u32, Transaction, Timestamp)>;
+
+    /// - This gets the list of guardians.
+    #[ink(message)]
+    fn guardians(
+        &self,
+    ) -> Vec<AccountID>;
+
+    /// - This gets the number of guardian vetoes needed to veto multisigtx.
+    #[ink(message)]
+    fn guardian_threshold(
+        &self,
+    ) -> u16;
+
+    /// - This gets the signature weight of a signatory (zero if not signatory).
+    #[ink(message)]
+    fn signatory_weight(
+        &self,
+        signatory: AccountId,
+    ) -> u16;
+
+    /// - This gets current signature weight collected for multisigtx.
+    #[ink(message)]
+    fn signature_weight(
+        &self,
+        proposal: u32,
+    ) -> u32;
+
+    /// - This gets whether an off-chain signed multisigtx with nonce was already submitted.
+    #[ink(message)]
+    fn nonce_used(
+        &self,
+        nonce: u64,
+    ) -> bool;
+
+    /// - This gets current number of signatures for multisigtx.
+    #[ink(message)]
+    fn signature_count(
+        &self,
+        proposal: u32,
+    ) -> u8;
+
+    /// - This gets a list of all signers so far on a multisigtx.
+    #[ink(message)]
+    fn check_signatures(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Vec<Signature>>;
+}
+
+/// - Internal multisig functions, for use by contract implementing the multisig.
+pub trait Internal {
+
+    /// - Checks whether function index is a multisig function of the contract.
+    /// - Contracts override this with the multisig functions they expose.
+    fn _is_multisig_function(
+        &self,
+        function: u8,
+    ) -> bool;
+
+    /// - Gets the contract's function index for a multisig-gated message of Multisig.
+    /// - Contracts override this; messages without an index fail with InvalidFunction.
+    fn _shared_function(
+        &self,
+        function: SharedFunction,
+    ) -> MultisigResult<u8>;
+
+    /// - Helper function for checking signature count, to be called by multisig-gated messages.
+    /// - Function must be the index of the calling message itself, never caller supplied,
+    /// so a proposal signed for one function cannot be spent by another.
+    /// - Marks the proposal complete if checks pass.
+    fn _check_multisig(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function that checks and completes a multisigtx, regardless of caller.
+    /// - For use by messages that verify signatories some other way.
+    fn _execute_multisig(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function to order multisigtx transaction, for contract order_multisigtx().
+    /// - Args are the SCALE-encoded arguments of the function being ordered
+    /// (excluding the trailing `proposal` argument).
+    /// - Returns the proposal id signatories sign and call with.
+    fn _order_multisigtx(
+        &mut self,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<u32>;
+
+    /// - Helper function for a multisigtx signer to sign, for contract sign_multisigtx().
+    /// - Signer must supply the same function and SCALE-encoded arguments as the order.
+    fn _sign_multisigtx(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function that sets the execution delay for a multisigtx function,
+    /// for contract set_execution_delay().
+    /// - Multisigtx args are SCALE-encoded (target, delay).
+    fn _set_execution_delay(
+        &mut self,
+        target: u8,
+        delay: Timestamp,
+        proposal: u32,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function to get the execution delay for a multisigtx function,
+    /// for contract execution_delay().
+    fn _execution_delay(
+        &self,
+        target: u8,
+    ) -> MultisigResult<Timestamp>;
+
+    /// - Helper function to get hash signatories sign off-chain, for contract proposal_hash().
+    /// - Hash is blake2x256 of SCALE-encoded (contract address, nonce, function, args).
+    fn _proposal_hash(
+        &self,
+        function: u8,
+        nonce: u64,
+        args: &[u8],
+    ) -> [u8; 32];
+
+    /// - Helper function to verify off-chain signatures and record signed multisigtx,
+    /// for contract submit_signatures().
+    /// - Signatures are 65 byte ECDSA signatures over _proposal_hash(function, nonce, args),
+    /// and must meet threshold.
+    /// - First signer stands in as orderer, and may not have another open order.
+    /// - Returns the proposal id of the recorded multisigtx.
+    fn _record_signed_multisigtx(
+        &mut self,
+        function: u8,
+        nonce: u64,
+        args: Vec<u8>,
+        signatures: Vec<[u8; 65]>,
+    ) -> MultisigResult<u32>;
+
+    /// - Helper function to check if account is signatory.
+    fn _is_signatory(
+        &self,
+        account: AccountId,
+    ) -> bool;
+
+    /// - Helper function to get multisigtx proposal by id.
+    fn _get_multisigtx(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Transaction>;
+
+    /// - Helper function to drop completed and stale proposals from open list.
+    fn _prune_multisigtxs(
+        &mut self,
+    );
+
+    /// - Helper function to enforce one open order per signatory.
+    /// - Returns CannotReorder if orderer has an open order.
+    fn _check_open_order(
+        &mut self,
+        orderer: AccountID,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function to check if multisigtx is too old to sign or call.
+    /// - Multisigtx with execution delay that met threshold goes stale
+    /// timelimit after delay passes.
+    fn _is_stale(
+        &self,
+        tx: &Transaction,
+        thistime: Timestamp,
+    ) -> bool;
+
+    /// - Helper function to get signature weight of account (zero if not signatory).
+    fn _signatory_weight(
+        &self,
+        account: AccountId,
+    ) -> u16;
+
+    /// - Helper function to sum weight of signatures by current signatories.
+    fn _signature_weight(
+        &self,
+        tx: &Transaction,
+    ) -> u32;
+
+    /// - Helper function to check that signatory weights are safe for threshold.
+    /// - No weight may meet threshold alone, and weights without the heaviest
+    /// must still meet threshold.
+    fn _check_weights(
+        &self,
+        weights: &[u16],
+        threshold: u16,
+    ) -> MultisigResult<()>;
+
+    /// - Helper function to queue multisigtx once signature threshold is met.
+    /// - Fixes the execution delay the multisigtx waits out; returns whether it was queued.
+    fn _queue_multisigtx(
+        &self,
+        tx: &mut Transaction,
+        thistime: Timestamp,
+    ) -> bool;
+
+    /// - Helper function to get time multisigtx with execution delay becomes callable.
+    fn _eta(
+        &self,
+        tx: &Transaction,
+    ) -> Timestamp;
+
+    /// - Emitted when a signatory orders a multisigtx.
+    fn _emit_multisig_ordered_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+    );
+
+    /// - Emitted when a signatory signs a multisigtx.
+    fn _emit_multisig_signed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _signer: AccountId,
+    );
+
+    /// - Emitted when a multisigtx with execution delay meets signature threshold.
+    fn _emit_multisig_queued_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _eta: Timestamp,
+    );
+
+    /// - Emitted when guardians veto a multisigtx.
+    fn _emit_multisig_vetoed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+    );
+
+    /// - Emitted when the orderer cancels a multisigtx.
+    fn _emit_multisig_cancelled_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+    );
+
+    /// - Emitted when a signatory revokes their signature on a multisigtx.
+    fn _emit_signature_revoked_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _signer: AccountId,
+    );
+
+    /// - Emitted when a multisig-gated message passes its multisigtx check.
+    fn _emit_multisig_executed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+        _executor: AccountId,
+    );
+
+    /// - Emitted when a signatory is added.
+    fn _emit_signatory_added_event(
+        &self,
+        _signatory: AccountId,
+    );
+
+    /// - Emitted when a signatory is removed.
+    fn _emit_signatory_removed_event(
+        &self,
+        _signatory: AccountId,
+    );
+
+    /// - Emitted when a signatory weight changes.
+    fn _emit_signatory_weight_changed_event(
+        &self,
+        _signatory: AccountId,
+        _weight: u16,
+    );
+
+    /// - Emitted when the signature threshold changes.
+    fn _emit_threshold_changed_event(
+        &self,
+        _threshold: u16,
+    );
+
+    /// - Emitted when the multisigtx timelimit changes.
+    fn _emit_timelimit_changed_event(
+        &self,
+        _timelimit: Timestamp,
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////
+//// implementations ///////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////
+
+impl<T: Storage<MultisigData>> Internal for T {
+
+    default fn _is_multisig_function(
+        &self,
+        _function: u8,
+    ) -> bool {
+
+        false
+    }
+
+    default fn _shared_function(
+        &self,
+        _function: SharedFunction,
+    ) -> MultisigResult<u8> {
+
+        Err(MultisigError::InvalidFunction)
+    }
+
+    default fn _check_multisig(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()> {
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(Self::env().caller()) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        self._execute_multisig(proposal, function, args)
+    }
+
+    default fn _execute_multisig(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()> {
+
+        let caller: AccountId = Self::env().caller();
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // get the proposal being called
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // if enough signatures had not been supplied, revert
+        if self._signature_weight(&tx) < self.data().threshold as u32 {
+
+            return Err(MultisigError::NotEnoughSignatures);
+        }
+
+        // if multisigtx is too old, then signature does not matter
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        // make sure contract exposes function
+        if !self._is_multisig_function(function) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        // signer must know they are signing for the right function
+        if function != tx.function {
+
+            return Err(MultisigError::WrongFunction);
+        }
+
+        // caller must call function with the arguments signatories agreed on
+        if args != tx.args {
+
+            return Err(MultisigError::WrongArguments);
+        }
+
+        // transaction must not have already been completed
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCalled);
+        }
+
+        // transaction must not have been cancelled
+        if tx.cancelled {
+
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        // transaction must not have been vetoed
+        if tx.vetoed {
+
+            return Err(MultisigError::TransactionVetoed);
+        }
+
+        // transaction for function with execution delay must be queued first
+        if tx.queued == 0 && self.data().delays.get(tx.function).unwrap_or(0) > 0 {
+
+            return Err(MultisigError::ExecutionDelayNotPassed);
+        }
+
+        // queued transaction must wait out the delay it was queued with
+        if tx.queued != 0 && thistime < self._eta(&tx) {
+
+            return Err(MultisigError::ExecutionDelayNotPassed);
+        }
+
+        // making it this far means that function is ready to call
+        // ...if called function fails, then tx will need to be reordered
+        tx.complete = true;
+        self.data().proposals.insert(proposal, &tx);
+        let mut open: Vec<u32> = self.data().open.get_or_default();
+        open.retain(|&id| id != proposal);
+        self.data().open.set(&open);
+
+        self._emit_multisig_executed_event(proposal, function, tx.orderer.address, caller);
+
+        Ok(())
+    }
+
+    default fn _order_multisigtx(
+        &mut self,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<u32> {
+
+        let caller: AccountID = AccountID { address: Self::env().caller() };
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(caller.address) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        // make sure contract exposes function
+        if !self._is_multisig_function(function) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        // signatory may have only one open order
+        self._check_open_order(caller)?;
+
+        // assign proposal id
+        let proposal: u32 = self.data().nextproposal.get_or_default();
+        match proposal.checked_add(1) {
+            Some(sum) => self.data().nextproposal.set(&sum),
+            None => return Err(MultisigError::Overflow),
+        };
+
+        // construct signature
+        let signature: Signature = Signature {
+            signer: caller,
+            time: thistime,
+        };
+
+        // add first signature to multisigtx transaction order
+        let tx: Transaction = Transaction {
+            orderer: caller,
+            signatures: Vec::from([signature]),
+            function: function,
+            time: thistime,
+            complete: false,
+            args: args,
+            cancelled: false,
+            queued: 0,
+            vetoes: Vec::new(),
+            vetoed: false,
+            delay: 0,
+        };
+        self.data().proposals.insert(proposal, &tx);
+        let mut open: Vec<u32> = self.data().open.get_or_default();
+        open.push(proposal);
+        self.data().open.set(&open);
+
+        self._emit_multisig_ordered_event(proposal, function, caller.address);
+
+        Ok(proposal)
+    }
+
+    default fn _sign_multisigtx(
+        &mut self,
+        proposal: u32,
+        function: u8,
+        args: Vec<u8>,
+    ) -> MultisigResult<()> {
+
+        let caller: AccountID = AccountID { address: Self::env().caller() };
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(caller.address) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        // get the proposal being signed
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // make sure contract exposes function
+        if !self._is_multisig_function(function) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        // signer must know they are signing for the right function
+        if function != tx.function {
+
+            return Err(MultisigError::WrongFunction);
+        }
+
+        // signer must know they are signing for the right arguments
+        if args != tx.args {
+
+            return Err(MultisigError::WrongArguments);
+        }
+
+        // no point in signing transaction that was already called
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCompleted);
+        }
+
+        // no point in signing transaction that was cancelled
+        if tx.cancelled {
+
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        // no point in signing transaction that was vetoed
+        if tx.vetoed {
+
+            return Err(MultisigError::TransactionVetoed);
+        }
+
+        // if multisigtx is too old, then signature does not matter
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        // make sure signatory has not already signed for the transaction
+        if tx.signatures.iter().any(|sig| sig.signer == caller) {
+
+            return Err(MultisigError::AlreadySigned);
+        }
+
+        // construct signature
+        let signature: Signature = Signature {
+            signer: caller,
+            time: thistime,
+        };
+
+        tx.signatures.push(signature);
+
+        // execution delay starts once threshold is met
+        let queued: bool = self._queue_multisigtx(&mut tx, thistime);
+
+        self.data().proposals.insert(proposal, &tx);
+
+        self._emit_multisig_signed_event(proposal, function, caller.address);
+        if queued && tx.delay > 0 {
+
+            self._emit_multisig_queued_event(proposal, function, self._eta(&tx));
+        }
+
+        Ok(())
+    }
+
+    default fn _set_execution_delay(
+        &mut self,
+        target: u8,
+        delay: Timestamp,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::SetExecutionDelay)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&(target, delay)))?;
+
+        // make sure function to delay is exposed by contract
+        if !self._is_multisig_function(target) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        self.data().delays.insert(target, &delay);
+
+        Ok(())
+    }
+
+    default fn _execution_delay(
+        &self,
+        target: u8,
+    ) -> MultisigResult<Timestamp> {
+
+        if !self._is_multisig_function(target) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        Ok(self.data().delays.get(target).unwrap_or(0))
+    }
+
+    default fn _proposal_hash(
+        &self,
+        function: u8,
+        nonce: u64,
+        args: &[u8],
+    ) -> [u8; 32] {
+
+        Self::env().hash_encoded::<Blake2x256, _>(
+            &(Self::env().account_id(), nonce, function, args)
+        )
+    }
+
+    default fn _record_signed_multisigtx(
+        &mut self,
+        function: u8,
+        nonce: u64,
+        args: Vec<u8>,
+        signatures: Vec<[u8; 65]>,
+    ) -> MultisigResult<u32> {
+
+        let thistime: Timestamp = Self::env().block_timestamp();
+        let hash: [u8; 32] = self._proposal_hash(function, nonce, &args);
+
+        // make sure contract exposes function
+        if !self._is_multisig_function(function) {
+
+            return Err(MultisigError::InvalidFunction);
+        }
+
+        // make sure signatures were not submitted before
+        if self.data().nonces.get(nonce).unwrap_or(false) {
+
+            return Err(MultisigError::NonceUsed);
+        }
+
+        // recover signers
+        let mut txsignatures: Vec<Signature> = Vec::new();
+        for signature in signatures.iter() {
+
+            let pubkey: [u8; 33] = match Self::env().ecdsa_recover(signature, &hash) {
+                Ok(pubkey) => pubkey,
+                Err(_) => return Err(MultisigError::InvalidSignature),
+            };
+            let signer: AccountID = AccountID {
+                address: AccountId::from(Self::env().hash_bytes::<Blake2x256>(&pubkey)),
+            };
+
+            // make sure signer is designated multisigtx account
+            if !self._is_signatory(signer.address) {
+
+                return Err(MultisigError::SignerNotSignatory);
+            }
+
+            // make sure signer is not counted twice
+            if txsignatures.iter().any(|sig| sig.signer == signer) {
+
+                return Err(MultisigError::AlreadySigned);
+            }
+
+            txsignatures.push(Signature {
+                signer: signer,
+                time: thistime,
+            });
+        }
+
+        // first signer stands in as orderer, so one open order rule applies to them
+        let orderer: AccountID = match txsignatures.first() {
+            Some(signature) => signature.signer,
+            None => return Err(MultisigError::NotEnoughSignatures),
+        };
+        self._check_open_order(orderer)?;
+
+        let mut tx: Transaction = Transaction {
+            orderer: orderer,
+            signatures: txsignatures,
+            function: function,
+            time: thistime,
+            complete: false,
+            args: args,
+            cancelled: false,
+            queued: 0,
+            vetoes: Vec::new(),
+            vetoed: false,
+            delay: 0,
+        };
+
+        // relayed signatures must meet threshold on their own
+        if !self._queue_multisigtx(&mut tx, thistime) {
+
+            return Err(MultisigError::NotEnoughSignatures);
+        }
+
+        // assign proposal id
+        let proposal: u32 = self.data().nextproposal.get_or_default();
+        match proposal.checked_add(1) {
+            Some(sum) => self.data().nextproposal.set(&sum),
+            None => return Err(MultisigError::Overflow),
+        };
+
+        // consume nonce so signatures cannot be replayed
+        self.data().nonces.insert(nonce, &true);
+
+        self.data().proposals.insert(proposal, &tx);
+        let mut open: Vec<u32> = self.data().open.get_or_default();
+        open.push(proposal);
+        self.data().open.set(&open);
+
+        self._emit_multisig_ordered_event(proposal, function, orderer.address);
+        if tx.delay > 0 {
+
+            self._emit_multisig_queued_event(proposal, function, self._eta(&tx));
+        }
+
+        Ok(proposal)
+    }
+
+    default fn _is_signatory(
+        &self,
+        account: AccountId,
+    ) -> bool {
+
+        self.data().signatories.contains(&AccountID { address: account })
+    }
+
+    default fn _get_multisigtx(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Transaction> {
+
+        match self.data().proposals.get(proposal) {
+            Some(tx) => Ok(tx),
+            None => Err(MultisigError::NoTransaction),
+        }
+    }
+
+    default fn _prune_multisigtxs(
+        &mut self,
+    ) {
+
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        let open: Vec<u32> = self.data().open.get_or_default();
+        let open: Vec<u32> = open.into_iter()
+            .filter(|&id| match self._get_multisigtx(id) {
+                Ok(tx) => !tx.complete && !tx.vetoed && !self._is_stale(&tx, thistime),
+                Err(_) => false,
+            })
+            .collect();
+
+        self.data().open.set(&open);
+    }
+
+    default fn _check_open_order(
+        &mut self,
+        orderer: AccountID,
+    ) -> MultisigResult<()> {
+
+        // forget proposals that can no longer be signed or called
+        self._prune_multisigtxs();
+
+        // this is important to prevent corrupted key from 'freezing out'
+        // other signatories' ability to order transaction
+        let open: Vec<u32> = self.data().open.get_or_default();
+        for id in open.iter() {
+
+            if self._get_multisigtx(*id)?.orderer == orderer {
+
+                return Err(MultisigError::CannotReorder);
+            }
+        }
+
+        Ok(())
+    }
+
+    default fn _is_stale(
+        &self,
+        tx: &Transaction,
+        thistime: Timestamp,
+    ) -> bool {
+
+        let timelimit: Timestamp = self.data().timelimit;
+
+        // queued multisigtx with execution delay has timelimit to be called after delay
+        if tx.queued != 0 && tx.delay > 0 {
+
+            let eta: Timestamp = self._eta(tx);
+            return thistime > eta && thistime - eta >= timelimit;
+        }
+
+        thistime - tx.time >= timelimit
+    }
+
+    default fn _signatory_weight(
+        &self,
+        account: AccountId,
+    ) -> u16 {
+
+        if !self._is_signatory(account) {
+
+            return 0;
+        }
+
+        self.data().weights.get(account).unwrap_or(1)
+    }
+
+    default fn _signature_weight(
+        &self,
+        tx: &Transaction,
+    ) -> u32 {
+
+        tx.signatures.iter()
+            .map(|sig| self._signatory_weight(sig.signer.address) as u32)
+            .sum()
+    }
+
+    default fn _check_weights(
+        &self,
+        weights: &[u16],
+        threshold: u16,
+    ) -> MultisigResult<()> {
+
+        let total: u32 = weights.iter().map(|&weight| weight as u32).sum();
+        let heaviest: u16 = weights.iter().copied().max().unwrap_or(0);
+
+        // no single signatory may approve multisigtx alone
+        if heaviest >= threshold {
+
+            return Err(MultisigError::WeightTooLarge);
+        }
+
+        // losing any one signatory must not lock the multisig
+        if total - (heaviest as u32) < threshold as u32 {
+
+            return Err(MultisigError::TooFewSignatories);
+        }
+
+        Ok(())
+    }
+
+    default fn _queue_multisigtx(
+        &self,
+        tx: &mut Transaction,
+        thistime: Timestamp,
+    ) -> bool {
+
+        if tx.queued != 0 || self._signature_weight(tx) < self.data().threshold as u32 {
+
+            return false;
+        }
+
+        tx.queued = thistime;
+        tx.delay = self.data().delays.get(tx.function).unwrap_or(0);
+
+        true
+    }
+
+    default fn _eta(
+        &self,
+        tx: &Transaction,
+    ) -> Timestamp {
+
+        tx.queued.saturating_add(tx.delay)
+    }
+
+    default fn _emit_multisig_ordered_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+    ) {}
+
+    default fn _emit_multisig_signed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _signer: AccountId,
+    ) {}
+
+    default fn _emit_multisig_queued_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _eta: Timestamp,
+    ) {}
+
+    default fn _emit_multisig_vetoed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+    ) {}
+
+    default fn _emit_multisig_cancelled_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+    ) {}
+
+    default fn _emit_signature_revoked_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _signer: AccountId,
+    ) {}
+
+    default fn _emit_multisig_executed_event(
+        &self,
+        _proposal: u32,
+        _function: u8,
+        _proposer: AccountId,
+        _executor: AccountId,
+    ) {}
+
+    default fn _emit_signatory_added_event(
+        &self,
+        _signatory: AccountId,
+    ) {}
+
+    default fn _emit_signatory_removed_event(
+        &self,
+        _signatory: AccountId,
+    ) {}
+
+    default fn _emit_signatory_weight_changed_event(
+        &self,
+        _signatory: AccountId,
+        _weight: u16,
+    ) {}
+
+    default fn _emit_threshold_changed_event(
+        &self,
+        _threshold: u16,
+    ) {}
+
+    default fn _emit_timelimit_changed_event(
+        &self,
+        _timelimit: Timestamp,
+    ) {}
+}
+
+impl<T: Storage<MultisigData>> Multisig for T {
+
+    default fn cancel_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        let caller: AccountID = AccountID { address: Self::env().caller() };
+
+        // get the proposal being cancelled
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // only orderer may cancel
+        if caller != tx.orderer {
+
+            return Err(MultisigError::CallerNotOrderer);
+        }
+
+        // cannot cancel transaction that was already called
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCompleted);
+        }
+
+        // cannot cancel transaction twice
+        if tx.cancelled {
+
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        // proposal stays open until stale, so orderer still cannot reorder
+        // ...this keeps a corrupted signatory from cycling order/cancel to jam the multisig
+        tx.cancelled = true;
+        self.data().proposals.insert(proposal, &tx);
+
+        self._emit_multisig_cancelled_event(proposal, tx.function, caller.address);
+
+        Ok(())
+    }
+
+    default fn revoke_signature(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        let caller: AccountID = AccountID { address: Self::env().caller() };
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(caller.address) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        // get the proposal being unsigned
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // no point in revoking signature on transaction that was already called
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCompleted);
+        }
+
+        // no point in revoking signature on transaction that was cancelled
+        if tx.cancelled {
+
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        // no point in revoking signature on transaction that was vetoed
+        if tx.vetoed {
+
+            return Err(MultisigError::TransactionVetoed);
+        }
+
+        // orderer signature is the order itself, so orderer must cancel instead
+        if caller == tx.orderer {
+
+            return Err(MultisigError::CallerIsOrderer);
+        }
+
+        // if multisigtx is too old, then signature does not matter
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        // make sure signatory signed the transaction
+        if !tx.signatures.iter().any(|sig| sig.signer == caller) {
+
+            return Err(MultisigError::NotSigned);
+        }
+
+        tx.signatures.retain(|sig| sig.signer != caller);
+
+        // dropping under threshold restarts execution delay when threshold is met again
+        if self._signature_weight(&tx) < self.data().threshold as u32 {
+
+            tx.queued = 0;
+            tx.delay = 0;
+        }
+
+        self.data().proposals.insert(proposal, &tx);
+
+        self._emit_signature_revoked_event(proposal, tx.function, caller.address);
+
+        Ok(())
+    }
+
+    default fn veto_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        let caller: AccountID = AccountID { address: Self::env().caller() };
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is guardian
+        if !self.data().guardians.get_or_default().contains(&caller) {
+
+            return Err(MultisigError::CallerNotGuardian);
+        }
+
+        // get the proposal being vetoed
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // no point in vetoing transaction that was already called
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCompleted);
+        }
+
+        // no point in vetoing transaction twice
+        if tx.vetoed {
+
+            return Err(MultisigError::TransactionVetoed);
+        }
+
+        // no point in vetoing transaction that cannot be called anymore
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        // make sure guardian has not already vetoed the transaction
+        if tx.vetoes.contains(&caller) {
+
+            return Err(MultisigError::AlreadyVetoed);
+        }
+
+        tx.vetoes.push(caller);
+
+        // veto takes once guardian threshold is met
+        let vetoed: bool = tx.vetoes.len() >= self.data().guardianthreshold.get_or_default() as usize;
+        if vetoed {
+
+            tx.vetoed = true;
+            let mut open: Vec<u32> = self.data().open.get_or_default();
+            open.retain(|&id| id != proposal);
+            self.data().open.set(&open);
+        }
+
+        self.data().proposals.insert(proposal, &tx);
+
+        if vetoed {
+
+            self._emit_multisig_vetoed_event(proposal, tx.function);
+        }
+
+        Ok(())
+    }
+
+    default fn queue_multisigtx(
+        &mut self,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(Self::env().caller()) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        // get the proposal being queued
+        let mut tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // no point in queueing transaction that was already called
+        if tx.complete {
+
+            return Err(MultisigError::TransactionAlreadyCompleted);
+        }
+
+        // no point in queueing transaction that was cancelled
+        if tx.cancelled {
+
+            return Err(MultisigError::TransactionCancelled);
+        }
+
+        // no point in queueing transaction that was vetoed
+        if tx.vetoed {
+
+            return Err(MultisigError::TransactionVetoed);
+        }
+
+        // if multisigtx is too old, then signature does not matter
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        // queueing again would restart delay
+        if tx.queued != 0 {
+
+            return Err(MultisigError::AlreadyQueued);
+        }
+
+        if !self._queue_multisigtx(&mut tx, thistime) {
+
+            return Err(MultisigError::NotEnoughSignatures);
+        }
+
+        self.data().proposals.insert(proposal, &tx);
+
+        if tx.delay > 0 {
+
+            self._emit_multisig_queued_event(proposal, tx.function, self._eta(&tx));
+        }
+
+        Ok(())
+    }
+
+    default fn set_guardians(
+        &mut self,
+        guardians: Vec<AccountId>,
+        threshold: u16,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::SetGuardians)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&(guardians.clone(), threshold)))?;
+
+        // threshold must be met by guardians, and guardians must have threshold
+        // ...no guardians and zero threshold disables vetoes
+        if threshold as usize > guardians.len() || (threshold == 0 && !guardians.is_empty()) {
+
+            return Err(MultisigError::InvalidGuardianThreshold);
+        }
+
+        let mut newguardians: Vec<AccountID> = Vec::new();
+        for guardian in guardians.iter() {
+
+            // make sure guardian is not zero address
+            if *guardian == AccountId::from([0_u8; 32]) {
+                return Err(MultisigError::IsZeroAddress)
+            }
+
+            let guardian: AccountID = AccountID { address: *guardian };
+            if !newguardians.contains(&guardian) {
+
+                newguardians.push(guardian);
+            }
+        }
+
+        // duplicates must not count toward threshold
+        if threshold as usize > newguardians.len() {
+
+            return Err(MultisigError::InvalidGuardianThreshold);
+        }
+
+        self.data().guardians.set(&newguardians);
+        self.data().guardianthreshold.set(&threshold);
+
+        Ok(())
+    }
+
+    default fn set_signatory_weight(
+        &mut self,
+        signatory: AccountId,
+        weight: u16,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::SetSignatoryWeight)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&(signatory, weight)))?;
+
+        // make sure signatory is designated multisigtx account
+        if !self._is_signatory(signatory) {
+
+            return Err(MultisigError::NoSignatory);
+        }
+
+        // zero weight signatory should be removed instead
+        if weight == 0 {
+
+            return Err(MultisigError::InvalidWeight);
+        }
+
+        // contract must maintain THRESHOLD + 1 signatories (in weight) at all times
+        let signatories: Vec<AccountID> = self.data().signatories.clone();
+        let weights: Vec<u16> = signatories.iter()
+            .map(|account| match account.address == signatory {
+                true => weight,
+                false => self._signatory_weight(account.address),
+            })
+            .collect();
+        let threshold: u16 = self.data().threshold;
+        self._check_weights(&weights, threshold)?;
+
+        self.data().weights.insert(signatory, &weight);
+
+        self._emit_signatory_weight_changed_event(signatory, weight);
+
+        Ok(())
+    }
+
+    default fn add_signatory(
+        &mut self,
+        signatory: AccountId,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // verify multisig good
+        let function: u8 = self._shared_function(SharedFunction::AddSignatory)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&signatory))?;
+
+        // make sure signatory is not zero address
+        if signatory == AccountId::from([0_u8; 32]) {
+            return Err(MultisigError::IsZeroAddress)
+        }
+
+        let signatory: AccountID = AccountID { address: signatory };
+
+        // make sure caller is designated multisigtx account
+        if self.data().signatories.contains(&signatory) {
+
+            return Err(MultisigError::AlreadySignatory);
+        }
+
+        self.data().signatories.push(signatory);
+
+        self._emit_signatory_added_event(signatory.address);
+
+        Ok(())
+    }
+
+    default fn remove_signatory(
+        &mut self,
+        signatory: AccountId,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::RemoveSignatory)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&signatory))?;
+
+        // make sure signatory is not zero address
+        if signatory == AccountId::from([0_u8; 32]) {
+            return Err(MultisigError::IsZeroAddress)
+        }
+
+        let signatory: AccountID = AccountID { address: signatory };
+
+        // make sure signatory is designated multisigtx account
+        if !self.data().signatories.contains(&signatory) {
+
+            return Err(MultisigError::NoSignatory);
+        }
+
+        // contract must maintain THRESHOLD + 1 signatories (in weight) at all times
+        let signatories: Vec<AccountID> = self.data().signatories.clone();
+        let weights: Vec<u16> = signatories.iter()
+            .filter(|&&account| account != signatory)
+            .map(|account| self._signatory_weight(account.address))
+            .collect();
+        let threshold: u16 = self.data().threshold;
+        self._check_weights(&weights, threshold)?;
+
+        self.data().signatories.retain(|&account| account != signatory);
+        self.data().weights.remove(signatory.address);
+
+        self._emit_signatory_removed_event(signatory.address);
+
+        Ok(())
+    }
+
+    default fn change_threshold(
+        &mut self,
+        threshold: u16,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::ChangeThreshold)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&threshold))?;
+
+        // make sure new threshold is greater then minimum
+        if threshold < THRESHOLD_MIN {
+
+            return Err(MultisigError::UnderThresholdMin);
+        }
+
+        // contract must maintain THRESHOLD + 1 signatories (in weight) at all times
+        let signatories: Vec<AccountID> = self.data().signatories.clone();
+        let weights: Vec<u16> = signatories.iter()
+            .map(|account| self._signatory_weight(account.address))
+            .collect();
+        self._check_weights(&weights, threshold)?;
+
+        self.data().threshold = threshold;
+
+        self._emit_threshold_changed_event(threshold);
+
+        Ok(())
+    }
+
+    default fn change_multisigtxtimelimit(
+        &mut self,
+        timelimit: Timestamp,
+        proposal: u32,
+    ) -> MultisigResult<()> {
+
+        // check multisig tx
+        let function: u8 = self._shared_function(SharedFunction::ChangeTimelimit)?;
+        let _ = self._check_multisig(proposal, function, scale::Encode::encode(&timelimit))?;
+
+        // make sure limit is respected
+        if timelimit < TIME_LIMIT_MIN {
+
+            return Err(MultisigError::UnderTimeMin);
+        }
+
+        self.data().timelimit = timelimit;
+
+        self._emit_timelimit_changed_event(timelimit);
+
+        Ok(())
+    }
+
+    default fn threshold(
+        &self,
+    ) -> u16 {
+
+        self.data().threshold
+    }
+
+    default fn multisigtimelimit(
+        &self,
+    ) -> Timestamp {
+
+        self.data().timelimit
+    }
+
+    default fn signatories(
+        &self,
+    ) -> MultisigResult<Vec<AccountID>> {
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(Self::env().caller()) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        Ok(self.data().signatories.iter().map(|sig| *sig ).collect())
+    }
+
+    default fn signatory_count(
+        &self,
+    ) -> u8 {
+
+        self.data().signatories.len() as u8
+    }
+
+    default fn multisigtx(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Transaction> {
+
+        self._get_multisigtx(proposal)
+    }
+
+    default fn open_multisigtxs(
+        &self,
+    ) -> Vec<(u32, Transaction)> {
+
+        let thistime: Timestamp = Self::env().block_timestamp();
+        let multisig: &MultisigData = self.data();
+
+        multisig.open.get_or_default().into_iter()
+            .filter_map(|id| multisig.proposals.get(id).map(|tx| (id, tx)))
+            .filter(|(_, tx)| !tx.complete && !tx.cancelled && !tx.vetoed && !self._is_stale(tx, thistime))
+            .collect()
+    }
+
+    default fn queued_multisigtxs(
+        &self,
+    ) -> Vec<(u32, Transaction, Timestamp)> {
+
+        self.open_multisigtxs().into_iter()
+            .filter(|(_, tx)| tx.queued != 0 && tx.delay > 0)
+            .map(|(id, tx)| {
+                let eta: Timestamp = self._eta(&tx);
+                (id, tx, eta)
+            })
+            .collect()
+    }
+
+    default fn guardians(
+        &self,
+    ) -> Vec<AccountID> {
+
+        self.data().guardians.get_or_default()
+    }
+
+    default fn guardian_threshold(
+        &self,
+    ) -> u16 {
+
+        self.data().guardianthreshold.get_or_default()
+    }
+
+    default fn signatory_weight(
+        &self,
+        signatory: AccountId,
+    ) -> u16 {
+
+        self._signatory_weight(signatory)
+    }
+
+    default fn signature_weight(
+        &self,
+        proposal: u32,
+    ) -> u32 {
+
+        match self.data().proposals.get(proposal) {
+            Some(tx) => self._signature_weight(&tx),
+            None => 0,
+        }
+    }
+
+    default fn nonce_used(
+        &self,
+        nonce: u64,
+    ) -> bool {
+
+        self.data().nonces.get(nonce).unwrap_or(false)
+    }
+
+    default fn signature_count(
+        &self,
+        proposal: u32,
+    ) -> u8 {
+
+        match self.data().proposals.get(proposal) {
+            Some(tx) => tx.signatures.len() as u8,
+            None => 0,
+        }
+    }
+
+    default fn check_signatures(
+        &self,
+        proposal: u32,
+    ) -> MultisigResult<Vec<Signature>> {
+
+        let thistime: Timestamp = Self::env().block_timestamp();
+
+        // make sure caller is designated multisigtx account
+        if !self._is_signatory(Self::env().caller()) {
+
+            return Err(MultisigError::CallerNotSignatory);
+        }
+
+        let tx: Transaction = self._get_multisigtx(proposal)?;
+
+        // if multisigtx is too old, then it doesn't matter who signed
+        if self._is_stale(&tx, thistime) {
+
+            return Err(MultisigError::TransactionStale);
+        }
+
+        Ok(tx.signatures.iter().map(|sig| *sig ).collect())
+    }
+}